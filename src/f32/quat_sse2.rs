@@ -1,11 +1,15 @@
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 use super::x86_utils::UnionCast;
-use super::{super::Align16, Vec3, Vec4};
+use super::{super::Align16, Mat3, Mat4, Vec3, Vec4};
 #[cfg(target_arch = "x86")]
 use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
-use std::{f32, mem::MaybeUninit};
+use std::{
+    f32,
+    mem::MaybeUninit,
+    ops::{Add, Deref, DerefMut, Mul, MulAssign, Neg, Sub},
+};
 
 /// A quaternion representing an orientation.
 ///
@@ -18,6 +22,45 @@ use std::{f32, mem::MaybeUninit};
 #[repr(C)]
 pub struct Quat(pub(crate) __m128);
 
+/// A POD value used to provide named field access to a `Quat`.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct XYZW {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Deref for Quat {
+    type Target = XYZW;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*(self as *const Self).cast() }
+    }
+}
+
+impl DerefMut for Quat {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *(self as *mut Self).cast() }
+    }
+}
+
+/// The rotation order to use when converting to or from Euler angles.
+///
+/// Each variant names the axes in the order the intrinsic rotations are applied, e.g.
+/// `XYZ` first rotates about `X`, then the new `Y`, then the new `Z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EulerRot {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
 impl Quat {
     /// Creates a new rotation quaternion.
     ///
@@ -44,6 +87,60 @@ impl Quat {
         unsafe { Self(_mm_set_ps(1.0, 0.0, 0.0, 0.0)) }
     }
 
+    /// Creates a new rotation quaternion from an axis and angle (in radians).
+    ///
+    /// # Preconditions
+    ///
+    /// `axis` must be normalized.
+    #[inline]
+    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Self {
+        glam_assert!(axis.is_normalized());
+        let (s, c) = (angle * 0.5).sin_cos();
+        let v = axis * s;
+        Self::new(v.x(), v.y(), v.z(), c)
+    }
+
+    /// Creates a new rotation quaternion around the X axis (in radians).
+    #[inline]
+    pub fn from_rotation_x(angle: f32) -> Self {
+        let (s, c) = (angle * 0.5).sin_cos();
+        Self::new(s, 0.0, 0.0, c)
+    }
+
+    /// Creates a new rotation quaternion around the Y axis (in radians).
+    #[inline]
+    pub fn from_rotation_y(angle: f32) -> Self {
+        let (s, c) = (angle * 0.5).sin_cos();
+        Self::new(0.0, s, 0.0, c)
+    }
+
+    /// Creates a new rotation quaternion around the Z axis (in radians).
+    #[inline]
+    pub fn from_rotation_z(angle: f32) -> Self {
+        let (s, c) = (angle * 0.5).sin_cos();
+        Self::new(0.0, 0.0, s, c)
+    }
+
+    /// Returns the rotation axis and angle (in radians) of `self`.
+    ///
+    /// If `self` is close to the identity quaternion an arbitrary normalized axis is returned
+    /// along with an angle of `0.0`.
+    #[inline]
+    pub fn to_axis_angle(self) -> (Vec3, f32) {
+        glam_assert!(self.is_normalized());
+
+        const EPSILON: f32 = 1.0e-8;
+        let (x, y, z, w) = self.into();
+        let w = w.max(-1.0).min(1.0);
+        let angle = 2.0 * w.acos();
+        let scale = (1.0 - w * w).sqrt();
+        if scale < EPSILON {
+            (Vec3::unit_x(), 0.0)
+        } else {
+            (Vec3::new(x, y, z) / scale, angle)
+        }
+    }
+
     /// Creates a new rotation quaternion from an unaligned `&[f32]`.
     ///
     /// # Preconditions
@@ -129,6 +226,348 @@ impl Quat {
         let b2 = Vec3::splat(b.dot(b));
         rhs * (w * w - b2) + b * (rhs.dot(b) * two) + b.cross(rhs) * (w * two)
     }
+
+    /// Returns the conjugate of `self`. For a unit quaternion this is the same as the inverse
+    /// but is cheaper to compute.
+    #[inline]
+    pub fn conjugate(self) -> Self {
+        const SIGN: UnionCast = UnionCast {
+            f32x4: [-0.0, -0.0, -0.0, 0.0],
+        };
+        unsafe { Self(_mm_xor_ps(self.0, SIGN.m128)) }
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// For a normalized quaternion this is the same as `conjugate`, but unlike `conjugate` this
+    /// is correct for quaternions that are not of unit length.
+    #[inline]
+    pub fn inverse(self) -> Self {
+        let length_sq = self.length_squared();
+        glam_assert!(length_sq != 0.0);
+        let inv = 1.0 / length_sq;
+        unsafe { Self(_mm_mul_ps(self.conjugate().0, _mm_set1_ps(inv))) }
+    }
+
+    /// Computes the dot product of `self` and `rhs`.
+    #[inline]
+    pub fn dot(self, rhs: Self) -> f32 {
+        unsafe { _mm_cvtss_f32(self.dot_as_vec4(rhs)) }
+    }
+
+    /// Computes the squared length of `self`.
+    #[inline]
+    pub fn length_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    /// Computes the length of `self`.
+    #[inline]
+    pub fn length(self) -> f32 {
+        self.length_squared().sqrt()
+    }
+
+    /// Returns `self` normalized to a unit quaternion.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        unsafe {
+            let len_sq = self.dot_as_vec4(self);
+            Self(_mm_div_ps(self.0, _mm_sqrt_ps(len_sq)))
+        }
+    }
+
+    /// Returns whether `self` is of unit length, within a tolerance small enough to be usable
+    /// in `glam_assert!` checks elsewhere in this type.
+    #[inline]
+    pub fn is_normalized(self) -> bool {
+        const THRESHOLD: f32 = 1e-4;
+        (self.length_squared() - 1.0).abs() <= THRESHOLD
+    }
+
+    #[inline]
+    fn dot_as_vec4(self, rhs: Self) -> __m128 {
+        unsafe {
+            let x2_y2_z2_w2 = _mm_mul_ps(self.0, rhs.0);
+            let y2_x2_w2_z2 = _mm_shuffle_ps(x2_y2_z2_w2, x2_y2_z2_w2, 0b10_11_00_01);
+            let z2w2x2y2_w2z2y2x2 = _mm_add_ps(x2_y2_z2_w2, y2_x2_w2_z2);
+            let z2_w2_x2_y2 = _mm_shuffle_ps(
+                z2w2x2y2_w2z2y2x2,
+                z2w2x2y2_w2z2y2x2,
+                0b00_01_10_11,
+            );
+            _mm_add_ps(z2w2x2y2_w2z2y2x2, z2_w2_x2_y2)
+        }
+    }
+
+    /// Performs a linear interpolation between `self` and `end`, based on the value `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`. When `s` is `1.0`, the result
+    /// will be equal to `end`. The result is normalized before being returned.
+    #[inline]
+    pub fn lerp(self, end: Self, s: f32) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+
+        unsafe {
+            const NEG_ZERO: UnionCast = UnionCast {
+                u32x4: [0x8000_0000; 4],
+            };
+            let start = self.0;
+            let end = end.0;
+            let dot = self.dot_as_vec4(Self(end));
+            // Calculate the bias, if the dot product is positive or zero, there is no bias
+            // but if it is negative, we want to flip the 'end' rotation XYZW components
+            let bias = _mm_and_ps(dot, NEG_ZERO.m128);
+            let interpolated = _mm_add_ps(
+                _mm_mul_ps(_mm_sub_ps(_mm_xor_ps(end, bias), start), _mm_set1_ps(s)),
+                start,
+            );
+            let result = Self(interpolated);
+            let len_sq = result.dot_as_vec4(result);
+            Self(_mm_div_ps(interpolated, _mm_sqrt_ps(len_sq)))
+        }
+    }
+
+    /// Performs a spherical linear interpolation between `self` and `end`, based on the value
+    /// `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`. When `s` is `1.0`, the result
+    /// will be equal to `end`.
+    ///
+    /// `self` and `end` are assumed to be normalized, and will not be re-normalized by this
+    /// function.
+    #[inline]
+    pub fn slerp(self, end: Self, s: f32) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+
+        // http://number-none.com/product/Understanding%20Slerp,%20Then%20Not%20Using%20It/
+        const DOT_THRESHOLD: f32 = 0.9995;
+
+        let dot = unsafe { _mm_cvtss_f32(self.dot_as_vec4(end)) };
+
+        // q and -q represent the same orientation, so if the dot product is negative, flip
+        // `end` (and its dot with `self`) to take the shorter arc between the two.
+        let (end, dot) = if dot < 0.0 {
+            (-end, -dot)
+        } else {
+            (end, dot)
+        };
+
+        if dot > DOT_THRESHOLD {
+            // assume lhs and rhs are very close together, fall back to lerp
+            return self.lerp(end, s);
+        }
+
+        let dot = dot.max(-1.0).min(1.0);
+        let theta = dot.acos() * s;
+
+        unsafe {
+            let v = _mm_sub_ps(end.0, _mm_mul_ps(self.0, _mm_set1_ps(dot)));
+            let q2 = Self(v);
+            let len_sq = q2.dot_as_vec4(q2);
+            let v = _mm_div_ps(v, _mm_sqrt_ps(len_sq));
+
+            let (sin_theta, cos_theta) = theta.sin_cos();
+            Self(_mm_add_ps(
+                _mm_mul_ps(self.0, _mm_set1_ps(cos_theta)),
+                _mm_mul_ps(v, _mm_set1_ps(sin_theta)),
+            ))
+        }
+    }
+
+    /// Creates a quaternion from the given Euler rotation sequence and the angles (in radians).
+    pub fn from_euler(euler: EulerRot, a: f32, b: f32, c: f32) -> Self {
+        match euler {
+            EulerRot::XYZ => Self::from_rotation_x(a)
+                .mul_quat(Self::from_rotation_y(b))
+                .mul_quat(Self::from_rotation_z(c)),
+            EulerRot::XZY => Self::from_rotation_x(a)
+                .mul_quat(Self::from_rotation_z(b))
+                .mul_quat(Self::from_rotation_y(c)),
+            EulerRot::YXZ => Self::from_rotation_y(a)
+                .mul_quat(Self::from_rotation_x(b))
+                .mul_quat(Self::from_rotation_z(c)),
+            EulerRot::YZX => Self::from_rotation_y(a)
+                .mul_quat(Self::from_rotation_z(b))
+                .mul_quat(Self::from_rotation_x(c)),
+            EulerRot::ZXY => Self::from_rotation_z(a)
+                .mul_quat(Self::from_rotation_x(b))
+                .mul_quat(Self::from_rotation_y(c)),
+            EulerRot::ZYX => Self::from_rotation_z(a)
+                .mul_quat(Self::from_rotation_y(b))
+                .mul_quat(Self::from_rotation_x(c)),
+        }
+    }
+
+    /// Returns the rotation angles (in radians) for the given Euler rotation sequence that
+    /// would reconstruct `self` via `from_euler`.
+    pub fn to_euler(self, euler: EulerRot) -> (f32, f32, f32) {
+        glam_assert!(self.is_normalized());
+
+        // reusable rotation matrix terms, see e.g.
+        // https://www.euclideanspace.com/maths/geometry/rotations/conversions/quaternionToMatrix/
+        let (x, y, z, w) = self.into();
+        let x2 = x + x;
+        let y2 = y + y;
+        let z2 = z + z;
+        let xx = x * x2;
+        let xy = x * y2;
+        let xz = x * z2;
+        let yy = y * y2;
+        let yz = y * z2;
+        let zz = z * z2;
+        let wx = w * x2;
+        let wy = w * y2;
+        let wz = w * z2;
+
+        let m00 = 1.0 - (yy + zz);
+        let m01 = xy - wz;
+        let m02 = xz + wy;
+        let m10 = xy + wz;
+        let m11 = 1.0 - (xx + zz);
+        let m12 = yz - wx;
+        let m20 = xz - wy;
+        let m21 = yz + wx;
+        let m22 = 1.0 - (xx + yy);
+
+        // avoids the ~1.0 but not quite hitting the asin domain due to rounding
+        const GIMBAL_THRESHOLD: f32 = 0.9999999;
+        let clamp = |v: f32| v.max(-1.0).min(1.0);
+
+        match euler {
+            EulerRot::XYZ => {
+                let angle_y = clamp(m02).asin();
+                let (angle_x, angle_z) = if m02.abs() < GIMBAL_THRESHOLD {
+                    ((-m12).atan2(m22), (-m01).atan2(m00))
+                } else {
+                    (m21.atan2(m11), 0.0)
+                };
+                (angle_x, angle_y, angle_z)
+            }
+            EulerRot::XZY => {
+                let angle_z = clamp(-m01).asin();
+                let (angle_x, angle_y) = if m01.abs() < GIMBAL_THRESHOLD {
+                    (m21.atan2(m11), m02.atan2(m00))
+                } else {
+                    (0.0, (-m20).atan2(m22))
+                };
+                (angle_x, angle_z, angle_y)
+            }
+            EulerRot::YXZ => {
+                let angle_x = clamp(-m12).asin();
+                let (angle_y, angle_z) = if m12.abs() < GIMBAL_THRESHOLD {
+                    (m02.atan2(m22), m10.atan2(m11))
+                } else {
+                    ((-m20).atan2(m00), 0.0)
+                };
+                (angle_y, angle_x, angle_z)
+            }
+            EulerRot::YZX => {
+                let angle_z = clamp(m10).asin();
+                let (angle_x, angle_y) = if m10.abs() < GIMBAL_THRESHOLD {
+                    ((-m12).atan2(m11), (-m20).atan2(m00))
+                } else {
+                    (0.0, m02.atan2(m22))
+                };
+                (angle_y, angle_z, angle_x)
+            }
+            EulerRot::ZXY => {
+                let angle_x = clamp(m21).asin();
+                let (angle_y, angle_z) = if m21.abs() < GIMBAL_THRESHOLD {
+                    ((-m20).atan2(m22), (-m01).atan2(m11))
+                } else {
+                    (0.0, m10.atan2(m00))
+                };
+                (angle_z, angle_x, angle_y)
+            }
+            EulerRot::ZYX => {
+                let angle_y = clamp(-m20).asin();
+                let (angle_x, angle_z) = if m20.abs() < GIMBAL_THRESHOLD {
+                    (m21.atan2(m22), m10.atan2(m00))
+                } else {
+                    (0.0, (-m01).atan2(m11))
+                };
+                (angle_z, angle_y, angle_x)
+            }
+        }
+    }
+
+    /// Creates a quaternion from a 3x3 rotation matrix.
+    pub fn from_rotation_mat3(mat: &Mat3) -> Self {
+        Self::from_rotation_axes(mat.x_axis, mat.y_axis, mat.z_axis)
+    }
+
+    /// Creates a quaternion from the upper left 3x3 rotation part of a 4x4 matrix.
+    pub fn from_rotation_mat4(mat: &Mat4) -> Self {
+        Self::from_rotation_axes(
+            mat.x_axis.truncate(),
+            mat.y_axis.truncate(),
+            mat.z_axis.truncate(),
+        )
+    }
+
+    fn from_rotation_axes(x_axis: Vec3, y_axis: Vec3, z_axis: Vec3) -> Self {
+        // Based on https://www.euclideanspace.com/maths/geometry/rotations/conversions/matrixToQuaternion/
+        // x_axis/y_axis/z_axis are matrix columns, so m_row_col = axis_col[row].
+        let (m00, m10, m20) = (x_axis.x(), x_axis.y(), x_axis.z());
+        let (m01, m11, m21) = (y_axis.x(), y_axis.y(), y_axis.z());
+        let (m02, m12, m22) = (z_axis.x(), z_axis.y(), z_axis.z());
+        let trace = m00 + m11 + m22;
+        if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Self::new((m21 - m12) / s, (m02 - m20) / s, (m10 - m01) / s, 0.25 * s)
+        } else if m00 > m11 && m00 > m22 {
+            let s = (1.0 + m00 - m11 - m22).sqrt() * 2.0;
+            Self::new(
+                0.25 * s,
+                (m01 + m10) / s,
+                (m02 + m20) / s,
+                (m21 - m12) / s,
+            )
+        } else if m11 > m22 {
+            let s = (1.0 + m11 - m00 - m22).sqrt() * 2.0;
+            Self::new(
+                (m01 + m10) / s,
+                0.25 * s,
+                (m12 + m21) / s,
+                (m02 - m20) / s,
+            )
+        } else {
+            let s = (1.0 + m22 - m00 - m11).sqrt() * 2.0;
+            Self::new(
+                (m02 + m20) / s,
+                (m12 + m21) / s,
+                0.25 * s,
+                (m10 - m01) / s,
+            )
+        }
+    }
+
+    /// Returns the equivalent 3x3 rotation matrix for `self`.
+    pub fn to_rotation_mat3(self) -> Mat3 {
+        glam_assert!(self.is_normalized());
+
+        let (x, y, z, w) = self.into();
+        let x2 = x + x;
+        let y2 = y + y;
+        let z2 = z + z;
+        let xx = x * x2;
+        let xy = x * y2;
+        let xz = x * z2;
+        let yy = y * y2;
+        let yz = y * z2;
+        let zz = z * z2;
+        let wx = w * x2;
+        let wy = w * y2;
+        let wz = w * z2;
+
+        Mat3::from_cols(
+            Vec3::new(1.0 - (yy + zz), xy + wz, xz - wy),
+            Vec3::new(xy - wz, 1.0 - (xx + zz), yz + wx),
+            Vec3::new(xz + wy, yz - wx, 1.0 - (xx + yy)),
+        )
+    }
 }
 
 impl From<Vec4> for Quat {
@@ -189,3 +628,181 @@ impl From<Quat> for [f32; 4] {
         }
     }
 }
+
+impl Mul<Quat> for Quat {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_quat(rhs)
+    }
+}
+
+impl MulAssign<Quat> for Quat {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_quat(rhs);
+    }
+}
+
+impl Mul<Vec3> for Quat {
+    type Output = Vec3;
+    #[inline]
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        self.mul_vec3(rhs)
+    }
+}
+
+impl Mul<f32> for Quat {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self {
+        unsafe { Self(_mm_mul_ps(self.0, _mm_set1_ps(rhs))) }
+    }
+}
+
+impl Neg for Quat {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        unsafe { Self(_mm_mul_ps(self.0, _mm_set1_ps(-1.0))) }
+    }
+}
+
+impl Add<Quat> for Quat {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        unsafe { Self(_mm_add_ps(self.0, rhs.0)) }
+    }
+}
+
+impl Sub<Quat> for Quat {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        unsafe { Self(_mm_sub_ps(self.0, rhs.0)) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_quat_eq(a: Quat, b: Quat, epsilon: f32) {
+        // `q` and `-q` represent the same orientation, so compare via the absolute dot product.
+        assert!(
+            a.dot(b).abs() > 1.0 - epsilon,
+            "{:?} != {:?}",
+            <(f32, f32, f32, f32)>::from(a),
+            <(f32, f32, f32, f32)>::from(b)
+        );
+    }
+
+    #[test]
+    fn test_lerp_endpoints() {
+        let a = Quat::from_rotation_y(0.3);
+        let b = Quat::from_rotation_y(1.2);
+        assert_quat_eq(a.lerp(b, 0.0), a, 1e-5);
+        assert_quat_eq(a.lerp(b, 1.0), b, 1e-5);
+    }
+
+    #[test]
+    fn test_slerp_endpoints() {
+        let a = Quat::from_rotation_y(0.3);
+        let b = Quat::from_rotation_y(1.2);
+        assert_quat_eq(a.slerp(b, 0.0), a, 1e-5);
+        assert_quat_eq(a.slerp(b, 1.0), b, 1e-5);
+    }
+
+    #[test]
+    fn test_slerp_negative_dot() {
+        // `b` is the sign-negated (but equivalent) representation of a rotation close to `a`,
+        // which must still take the shorter arc rather than the long way around.
+        let a = Quat::identity();
+        let b = -Quat::from_rotation_y(0.1);
+        let result = a.slerp(b, 0.5);
+        let expected = Quat::from_rotation_y(0.05);
+        assert_quat_eq(result, expected, 1e-4);
+    }
+
+    #[test]
+    fn test_axis_angle_round_trip() {
+        let q = Quat::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), 1.0);
+        let (axis, angle) = q.to_axis_angle();
+        assert!((axis.x() - 0.0).abs() < 1e-5);
+        assert!((axis.y() - 1.0).abs() < 1e-5);
+        assert!((axis.z() - 0.0).abs() < 1e-5);
+        assert!((angle - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_axis_angle_denormalized_w() {
+        // `w` slightly over 1.0 simulates the floating point "error creep" this type's doc
+        // comment warns about; `to_axis_angle` must not return a NaN axis/angle for it.
+        let q = Quat::new(0.0, 0.0, 0.0, 1.0 + 1e-7);
+        let (axis, angle) = q.to_axis_angle();
+        assert!(!angle.is_nan());
+        assert!(!axis.x().is_nan() && !axis.y().is_nan() && !axis.z().is_nan());
+    }
+
+    #[test]
+    fn test_euler_round_trip() {
+        const ORDERS: [EulerRot; 6] = [
+            EulerRot::XYZ,
+            EulerRot::XZY,
+            EulerRot::YXZ,
+            EulerRot::YZX,
+            EulerRot::ZXY,
+            EulerRot::ZYX,
+        ];
+        let (a, b, c) = (0.3, -0.5, 0.7);
+        for order in ORDERS.iter().copied() {
+            let q = Quat::from_euler(order, a, b, c);
+            let (a2, b2, c2) = q.to_euler(order);
+            let q2 = Quat::from_euler(order, a2, b2, c2);
+            assert_quat_eq(q, q2, 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_euler_gimbal_lock() {
+        // pushes the middle angle to the gimbal-lock boundary for each order
+        const ORDERS: [EulerRot; 6] = [
+            EulerRot::XYZ,
+            EulerRot::XZY,
+            EulerRot::YXZ,
+            EulerRot::YZX,
+            EulerRot::ZXY,
+            EulerRot::ZYX,
+        ];
+        let half_pi = std::f32::consts::FRAC_PI_2;
+        for order in ORDERS.iter().copied() {
+            let q = Quat::from_euler(order, 0.4, half_pi, -0.6);
+            let (a2, b2, c2) = q.to_euler(order);
+            let q2 = Quat::from_euler(order, a2, b2, c2);
+            assert_quat_eq(q, q2, 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_rotation_mat3_round_trip() {
+        let q = Quat::from_euler(EulerRot::XYZ, 0.3, -0.6, 1.1);
+        let m = q.to_rotation_mat3();
+        let q2 = Quat::from_rotation_mat3(&m);
+        assert_quat_eq(q, q2, 1e-4);
+    }
+
+    #[test]
+    fn test_rotation_mat3_round_trip_preserves_vectors() {
+        // guards against the transposed row/col indexing regression in `from_rotation_axes`.
+        let q = Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), 1.2);
+        let v = Vec3::new(1.0, 0.0, 0.0);
+        let m = q.to_rotation_mat3();
+        let q2 = Quat::from_rotation_mat3(&m);
+        let expected = q.mul_vec3(v);
+        let actual = q2.mul_vec3(v);
+        assert!((expected.x() - actual.x()).abs() < 1e-4);
+        assert!((expected.y() - actual.y()).abs() < 1e-4);
+        assert!((expected.z() - actual.z()).abs() < 1e-4);
+    }
+}